@@ -0,0 +1,64 @@
+/*!
+   Per-chain configuration for a Cosmos SDK chain, as parsed from the
+   relayer's config file. [`GasConfig`](crate::chain::cosmos::types::gas::GasConfig)
+   is derived from this via `GasConfig::from(&CosmosSdkConfig)`.
+*/
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::chain::cosmos::types::gas_price_oracle::{GasPriceOracleConfig, GasPriceSpeed};
+use crate::chain::cosmos::types::profitability::ProfitabilityConfig;
+use crate::config::dynamic_gas::DynamicGasPrice;
+use crate::config::GasMultiplier;
+use crate::config::GasPrice;
+
+#[derive(Debug, Clone)]
+pub struct CosmosSdkConfig {
+    pub id: ChainId,
+
+    /// The default amount of gas to use when the relayer cannot simulate a
+    /// transaction to estimate the gas amount needed. Defaults to `max_gas`.
+    pub default_gas: Option<u64>,
+
+    /// The maximum amount of gas the relayer is willing to pay for a
+    /// transaction. Defaults to `DEFAULT_MAX_GAS`.
+    pub max_gas: Option<u64>,
+
+    /// The multiplier applied to the gas amount returned by simulation.
+    pub gas_multiplier: Option<GasMultiplier>,
+
+    /// The static price paid per unit of gas, used for gas estimation and
+    /// accounting, in the chain's native token.
+    pub gas_price: GasPrice,
+
+    /// The price actually paid for transaction fees, when the chain accepts
+    /// a denomination distinct from its native gas/accounting token (e.g.
+    /// an IBC-transferred token). Defaults to `gas_price` when unset.
+    pub fee_price: Option<GasPrice>,
+
+    /// The address of a fee granter that covers transaction fees on behalf
+    /// of the relayer's wallet. Works for the configured `fee_price` denom
+    /// just as it does for the native `gas_price` denom: the granter only
+    /// needs to hold a fee allowance in whichever denom `fee_price`
+    /// resolves to, since `Fee.granter` is just an address and carries no
+    /// denom of its own.
+    pub fee_granter: Option<String>,
+
+    /// Configuration for deriving a dynamic gas price, either from the
+    /// chain's own EIP-1559-style fee market or, when `oracle` below is
+    /// also set, from an external gas price oracle.
+    pub dynamic_gas_price: DynamicGasPrice,
+
+    /// The speed category to select from the gas price oracle, when
+    /// `dynamic_gas_price` is enabled and `oracle` is set.
+    pub gas_price_speed: GasPriceSpeed,
+
+    /// The HTTP gas price oracle to poll. Only consulted when
+    /// `dynamic_gas_price` is enabled; ignored otherwise.
+    pub oracle: Option<GasPriceOracleConfig>,
+
+    /// Thresholds for gating packet relaying on ICS29 reward vs. estimated
+    /// gas cost. See [`ProfitabilityConfig`]; disabled by default, so
+    /// relaying behaves as before unless a chain opts in.
+    pub profitability: ProfitabilityConfig,
+}
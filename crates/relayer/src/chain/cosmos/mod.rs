@@ -0,0 +1,65 @@
+/*!
+   Cosmos SDK chain support: fee computation for submitted transactions.
+*/
+
+pub mod config;
+pub mod types;
+
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::cosmos::tx::v1beta1::Fee;
+
+use crate::chain::cosmos::config::CosmosSdkConfig;
+use crate::chain::cosmos::types::gas::{fee_for_gas, GasConfig};
+use crate::chain::cosmos::types::profitability::RelayDecision;
+use crate::config::GasPrice;
+use crate::error::Error;
+
+/// The `Coin` amount owed for `estimated_gas` at `price`, rounded up so the
+/// relayer never under-pays and risks a chain rejecting the tx for
+/// insufficient fees.
+pub fn calculate_fee(estimated_gas: u64, price: &GasPrice) -> Coin {
+    let amount = (estimated_gas as f64 * price.price).ceil() as u128;
+
+    Coin {
+        denom: price.denom().to_string(),
+        amount: amount.to_string(),
+    }
+}
+
+/// Build the `GasConfig` a `CosmosSdkChain` uses for the lifetime of its
+/// connection to `config.id`, spawning the gas price oracle poller (a no-op
+/// when no `oracle` is configured) now that we're running inside the
+/// chain's own Tokio runtime. This is the call site [`GasConfig::from`] and
+/// [`GasConfig::spawn_oracle_poller`] are split apart for: bootstrapping a
+/// chain runs here, never from a context (like `hermes config validate`)
+/// where no reactor is running yet.
+pub fn init_gas_config(config: &CosmosSdkConfig) -> GasConfig {
+    let mut gas_config = GasConfig::from(config);
+    gas_config.spawn_oracle_poller(config);
+    gas_config
+}
+
+/// Build the `Fee` to submit for relaying a packet estimated to cost
+/// `estimated_gas`, or `None` if `gas_config.profitability` is enabled and
+/// `reward` (the packet's claimable ICS29 fee, if any) doesn't clear the
+/// configured profit thresholds -- in which case the packet should be
+/// deferred rather than relayed at a loss. Packets with no ICS29 reward
+/// skip the gate entirely and are always relayed, same as before
+/// profitability-aware relaying existed.
+pub fn fee_for_relay(
+    gas_config: &GasConfig,
+    estimated_gas: u64,
+    reward: Option<&Coin>,
+) -> Result<Option<Fee>, Error> {
+    if let Some(reward) = reward {
+        let decision = gas_config
+            .profitability
+            .evaluate(gas_config, estimated_gas, reward)?;
+
+        if decision == RelayDecision::Defer {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(fee_for_gas(gas_config, estimated_gas)))
+}
@@ -1,7 +1,14 @@
+use alloc::sync::Arc;
+use core::time::Duration;
+
 use ibc_proto::cosmos::tx::v1beta1::Fee;
 
 use crate::chain::cosmos::calculate_fee;
 use crate::chain::cosmos::config::CosmosSdkConfig;
+use crate::chain::cosmos::types::gas_price_oracle::{
+    GasPriceOracle, GasPricePoller, GasPriceSpeed, HttpGasPriceOracle,
+};
+use crate::chain::cosmos::types::profitability::ProfitabilityConfig;
 use crate::config::dynamic_gas::DynamicGasPrice;
 use crate::config::GasPrice;
 
@@ -10,17 +17,48 @@ const DEFAULT_MAX_GAS: u64 = 400_000;
 
 const DEFAULT_FEE_GRANTER: &str = "";
 
+/// If the gas price oracle's cached value is older than this, it is
+/// considered stale and the static configured price is used instead. Used
+/// when the chain's `oracle` config doesn't specify its own `max_age`.
+pub(crate) const DEFAULT_ORACLE_MAX_AGE: Duration = Duration::from_secs(30);
+
 #[derive(Debug, Clone)]
 pub struct GasConfig {
     pub default_gas: u64,
     pub max_gas: u64,
     pub gas_multiplier: f64,
     pub gas_price: GasPrice,
+    /// The price used to build the `Fee` amount that actually gets paid.
+    /// Equal to `gas_price` unless the chain is configured with a
+    /// `fee_price` distinct from its native gas/accounting token, e.g. a
+    /// chain that accepts an IBC-transferred token as fee payment.
+    pub fee_price: GasPrice,
     pub max_fee: Fee,
     pub fee_granter: String,
     pub dynamic_gas_price: DynamicGasPrice,
+    /// The speed category to select from the gas price oracle, when one is
+    /// configured as the `dynamic_gas_price` source.
+    pub gas_price_speed: GasPriceSpeed,
+    /// The background poller caching the oracle's latest result, spawned
+    /// from the chain's `oracle` config when `dynamic_gas_price` is
+    /// enabled. `None` when no oracle is configured, in which case
+    /// `effective_gas_price`/`effective_fee_price` always use the static
+    /// configured price.
+    pub oracle_poller: Option<Arc<GasPricePoller>>,
+    /// How old the oracle poller's cached value may get before it is
+    /// treated as stale, taken from the chain's `oracle` config.
+    pub oracle_max_age: Duration,
+    /// See [`CosmosSdkConfig::profitability`](CosmosSdkConfig::profitability).
+    pub profitability: ProfitabilityConfig,
 }
 
+/// Builds a `GasConfig` with no oracle poller spawned yet: `From` stays a
+/// plain, synchronous conversion, since [`GasPricePoller::spawn`] calls
+/// [`tokio::spawn`] internally and would panic with "no reactor running"
+/// if triggered from a `From::from` invoked outside a Tokio runtime (e.g.
+/// from `hermes config validate`). Call
+/// [`GasConfig::spawn_oracle_poller`] once, from inside the chain runtime,
+/// to actually start polling.
 impl<'a> From<&'a CosmosSdkConfig> for GasConfig {
     fn from(config: &'a CosmosSdkConfig) -> Self {
         Self {
@@ -28,13 +66,89 @@ impl<'a> From<&'a CosmosSdkConfig> for GasConfig {
             max_gas: max_gas_from_config(config),
             gas_multiplier: gas_multiplier_from_config(config),
             gas_price: config.gas_price.clone(),
+            fee_price: fee_price_from_config(config),
             max_fee: max_fee_from_config(config),
             fee_granter: fee_granter_from_config(config),
             dynamic_gas_price: config.dynamic_gas_price,
+            gas_price_speed: config.gas_price_speed,
+            oracle_poller: None,
+            oracle_max_age: oracle_max_age_from_config(config),
+            profitability: config.profitability,
         }
     }
 }
 
+impl GasConfig {
+    /// Spawn the background gas-price-oracle poller for this config (see
+    /// [`oracle_poller_from_config`]) and attach it to `self`. A no-op when
+    /// `dynamic_gas_price` is disabled or no `oracle` endpoint is
+    /// configured.
+    ///
+    /// This must be called from within a Tokio runtime. It is kept
+    /// separate from [`GasConfig::from`] precisely so that constructing a
+    /// `GasConfig` never has this requirement; only opting a chain into
+    /// the dynamic gas price oracle does.
+    pub fn spawn_oracle_poller(&mut self, config: &CosmosSdkConfig) {
+        self.oracle_poller = oracle_poller_from_config(config);
+    }
+
+    /// The gas price used for gas-estimation/accounting purposes: the
+    /// oracle's category-selected price when an oracle is wired in and its
+    /// cached value is still fresh, falling back to the static `gas_price`
+    /// otherwise so that a stale or unreachable oracle never stalls
+    /// relaying. Intended for the tx-simulation step that checks a
+    /// transaction against a chain's minimum gas price, as distinct from
+    /// [`effective_fee_price`](Self::effective_fee_price), which prices the
+    /// `Fee` that's actually paid.
+    pub fn effective_gas_price(&self) -> GasPrice {
+        self.oracle_price_in(self.gas_price.denom())
+            .unwrap_or_else(|| self.gas_price.clone())
+    }
+
+    /// The price `fee_for_gas` uses to build the `Fee` that actually gets
+    /// paid: the oracle's category-selected price when the oracle is fresh
+    /// *and* quotes prices in the configured fee denom; the static
+    /// `fee_price` otherwise. An oracle configured for the native gas
+    /// token (see [`GasPriceOracleConfig::denom`](crate::chain::cosmos::types::gas_price_oracle::GasPriceOracleConfig))
+    /// never silently overrides a configured alternate `fee_price` denom,
+    /// since `GasPricePoller::get` refuses to answer for a denom it isn't
+    /// configured for.
+    pub fn effective_fee_price(&self) -> GasPrice {
+        self.oracle_price_in(self.fee_price.denom())
+            .unwrap_or_else(|| self.fee_price.clone())
+    }
+
+    fn oracle_price_in(&self, denom: &str) -> Option<GasPrice> {
+        self.oracle_poller
+            .as_ref()?
+            .get(self.gas_price_speed, denom, self.oracle_max_age)
+    }
+}
+
+/// Build the `Fee` to submit for a transaction estimated to cost
+/// `estimated_gas`, consulting the gas price oracle (when configured and
+/// fresh) via [`GasConfig::effective_fee_price`] instead of always using
+/// the static configured price. This is the per-transaction counterpart to
+/// [`max_fee_from_config`]'s conservative, statically-computed upper bound.
+pub fn fee_for_gas(gas_config: &GasConfig, estimated_gas: u64) -> Fee {
+    Fee {
+        amount: vec![fee_coin_for_gas(gas_config, estimated_gas)],
+        gas_limit: estimated_gas,
+        payer: "".to_string(),
+        granter: gas_config.fee_granter.clone(),
+    }
+}
+
+/// The fee amount alone, in the form [`ProfitabilityConfig::evaluate`](crate::chain::cosmos::types::profitability::ProfitabilityConfig::evaluate)
+/// needs to weigh against an ICS29 reward, computed the same way
+/// [`fee_for_gas`] computes the `Fee` it wraps it in.
+pub(crate) fn fee_coin_for_gas(
+    gas_config: &GasConfig,
+    estimated_gas: u64,
+) -> ibc_proto::cosmos::base::v1beta1::Coin {
+    calculate_fee(estimated_gas, &gas_config.effective_fee_price())
+}
+
 /// The default amount of gas the relayer is willing to pay for a transaction,
 /// when it cannot simulate the tx and therefore estimate the gas amount needed.
 pub fn default_gas_from_config(config: &CosmosSdkConfig) -> u64 {
@@ -67,11 +181,58 @@ fn fee_granter_from_config(config: &CosmosSdkConfig) -> String {
         .to_string()
 }
 
+/// The price used to pay transaction fees. Defaults to `gas_price` so that
+/// chains without a `fee_price` override behave exactly as before; chains
+/// that accept fees in a distinct denomination (e.g. an IBC-transferred
+/// token) configure their own `fee_price`, decoupled from the denom used
+/// for gas-estimation/accounting balances.
+fn fee_price_from_config(config: &CosmosSdkConfig) -> GasPrice {
+    config
+        .fee_price
+        .clone()
+        .unwrap_or_else(|| config.gas_price.clone())
+}
+
+/// Spawn the background [`GasPricePoller`] when `dynamic_gas_price` is
+/// enabled and the chain has an `oracle` endpoint configured to poll.
+/// Returns `None` otherwise, in which case the oracle subsystem is
+/// entirely inert and `GasConfig` always uses its static prices.
+fn oracle_poller_from_config(config: &CosmosSdkConfig) -> Option<Arc<GasPricePoller>> {
+    if !config.dynamic_gas_price.enabled {
+        return None;
+    }
+
+    let oracle_config = config.oracle.as_ref()?;
+
+    let oracle: Arc<dyn GasPriceOracle> = Arc::new(HttpGasPriceOracle::new(
+        oracle_config.endpoint.clone(),
+        oracle_config.poll_interval,
+    ));
+
+    Some(Arc::new(GasPricePoller::spawn(
+        config.id.to_string(),
+        oracle,
+        oracle_config.poll_interval,
+        oracle_config.denom.clone(),
+    )))
+}
+
+/// How old the oracle poller's cached value may get before it is treated as
+/// stale, taken from the chain's `oracle` config when set.
+fn oracle_max_age_from_config(config: &CosmosSdkConfig) -> Duration {
+    config
+        .oracle
+        .as_ref()
+        .map(|oracle_config| oracle_config.max_age)
+        .unwrap_or(DEFAULT_ORACLE_MAX_AGE)
+}
+
 fn max_fee_from_config(config: &CosmosSdkConfig) -> Fee {
     let max_gas = max_gas_from_config(config);
 
-    // The maximum fee the relayer pays for a transaction
-    let max_fee_in_coins = calculate_fee(max_gas, &config.gas_price);
+    // The maximum fee the relayer pays for a transaction, in the configured
+    // fee denom (the fee granter, if any, covers fees in this same denom).
+    let max_fee_in_coins = calculate_fee(max_gas, &fee_price_from_config(config));
 
     let fee_granter = fee_granter_from_config(config);
 
@@ -0,0 +1,379 @@
+/*!
+   An external gas-price oracle that can be polled on a timer to derive a
+   [`GasPrice`] for a chain, as an alternative to the chain's own
+   EIP-1559-style fee market (see [`DynamicGasPrice`](crate::config::dynamic_gas::DynamicGasPrice)).
+*/
+
+use alloc::sync::Arc;
+use core::time::Duration;
+use std::sync::RwLock;
+use std::time::Instant;
+
+use serde::Deserialize;
+use tokio::task::JoinHandle;
+use tokio::time::interval;
+use tracing::{debug, warn};
+
+use crate::config::GasPrice;
+use crate::error::Error;
+
+/// The speed category used to select a price out of the set returned
+/// by a [`GasPriceOracle`]. Configured per chain as `gas_price_speed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GasPriceSpeed {
+    Safe,
+    Standard,
+    Fast,
+    Fastest,
+}
+
+impl Default for GasPriceSpeed {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// The set of gas price categories reported by a [`GasPriceOracle`], along
+/// with the chain's recommended base fee.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct GasPriceCategories {
+    #[serde(rename = "safeLow")]
+    pub safe: f64,
+    pub standard: f64,
+    pub fast: f64,
+    pub fastest: f64,
+    #[serde(rename = "recommendedBaseFee")]
+    pub recommended_base_fee: f64,
+}
+
+impl GasPriceCategories {
+    /// Select the price for the given [`GasPriceSpeed`].
+    pub fn price_for(&self, speed: GasPriceSpeed) -> f64 {
+        match speed {
+            GasPriceSpeed::Safe => self.safe,
+            GasPriceSpeed::Standard => self.standard,
+            GasPriceSpeed::Fast => self.fast,
+            GasPriceSpeed::Fastest => self.fastest,
+        }
+    }
+}
+
+/// Per-chain configuration for the HTTP gas price oracle: where to poll,
+/// how often, and how old a cached value may get before it is considered
+/// unusable. Configured per chain as `oracle`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GasPriceOracleConfig {
+    /// The HTTP endpoint returning the `{ safeLow, standard, fast, fastest,
+    /// recommendedBaseFee }` JSON document.
+    pub endpoint: String,
+    /// The denom `endpoint`'s prices are quoted in. [`GasPricePoller::get`]
+    /// refuses to answer for any other denom, so an oracle that only
+    /// prices the native gas token can never be mistaken for a quote on a
+    /// chain's distinct `fee_price` denom.
+    pub denom: String,
+    /// How often the poller queries `endpoint`.
+    #[serde(with = "humantime_serde")]
+    pub poll_interval: Duration,
+    /// How old a cached value may be before [`GasPricePoller::get`] treats
+    /// it as stale and falls back to the static `gas_price`.
+    #[serde(with = "humantime_serde", default = "default_max_age")]
+    pub max_age: Duration,
+}
+
+/// Used when a chain sets `oracle` but omits `max_age`; matches
+/// `GasConfig`'s own fallback when no `oracle` is configured at all.
+fn default_max_age() -> Duration {
+    crate::chain::cosmos::types::gas::DEFAULT_ORACLE_MAX_AGE
+}
+
+/// A source of dynamic gas prices, external to the chain's own fee market.
+///
+/// Implementations are expected to be cheap to clone and safe to call
+/// concurrently, as a single instance is shared by the [`GasPricePoller`]
+/// across polling ticks.
+#[async_trait::async_trait]
+pub trait GasPriceOracle: Send + Sync + 'static {
+    /// Query the oracle for the current gas price categories on `chain_id`.
+    async fn query(&self, chain_id: &str) -> Result<GasPriceCategories, Error>;
+}
+
+/// A [`GasPriceOracle`] backed by an HTTP endpoint returning a JSON document
+/// shaped like `{ safeLow, standard, fast, fastest, recommendedBaseFee }`.
+#[derive(Debug, Clone)]
+pub struct HttpGasPriceOracle {
+    client: reqwest::Client,
+    endpoint: String,
+}
+
+impl HttpGasPriceOracle {
+    /// Build an oracle client that times out a request after `timeout`, so
+    /// an endpoint that accepts a connection but never responds still gets
+    /// retried on [`GasPricePoller`]'s next tick instead of blocking the
+    /// poller indefinitely and leaving the cache stuck going stale forever.
+    pub fn new(endpoint: String, timeout: Duration) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(timeout)
+                .build()
+                .unwrap_or_default(),
+            endpoint,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl GasPriceOracle for HttpGasPriceOracle {
+    async fn query(&self, chain_id: &str) -> Result<GasPriceCategories, Error> {
+        let response = self
+            .client
+            .get(&self.endpoint)
+            .send()
+            .await
+            .map_err(|e| Error::gas_price_oracle(chain_id.to_string(), e.to_string()))?;
+
+        response
+            .json::<GasPriceCategories>()
+            .await
+            .map_err(|e| Error::gas_price_oracle(chain_id.to_string(), e.to_string()))
+    }
+}
+
+/// The latest value produced by a [`GasPricePoller`], along with the instant
+/// it was fetched at so that staleness can be derived from it.
+#[derive(Debug, Clone, Copy)]
+struct CachedGasPrice {
+    categories: GasPriceCategories,
+    fetched_at: Instant,
+}
+
+/// Polls a [`GasPriceOracle`] on a timer and caches the latest result, so
+/// that the fee-calculation hot path never has to wait on a network call.
+///
+/// If the cached value becomes older than `max_age`, [`GasPricePoller::get`]
+/// returns `None` and callers are expected to fall back to the chain's
+/// static `gas_price`.
+pub struct GasPricePoller {
+    /// The denom the wrapped oracle's prices are quoted in. [`Self::get`]
+    /// only ever answers for this denom, so a caller asking in a
+    /// different denom (e.g. a chain's distinct `fee_price`) reliably
+    /// gets `None` rather than a price scaled for the wrong token.
+    denom: String,
+    cache: Arc<RwLock<Option<CachedGasPrice>>>,
+    /// The polling task spawned by [`Self::spawn`], aborted on `Drop` so
+    /// that rebuilding a chain's `GasConfig` (e.g. on a config reload)
+    /// doesn't leak a poller that outlives every handle to it. `None` for
+    /// a poller not backed by a real task (only used in tests).
+    handle: Option<JoinHandle<()>>,
+}
+
+impl GasPricePoller {
+    /// Spawn a background task that polls `oracle` for `chain_id` every
+    /// `poll_interval`, caching the latest successful result. `denom` is
+    /// the denom `oracle`'s prices are quoted in.
+    pub fn spawn(
+        chain_id: String,
+        oracle: Arc<dyn GasPriceOracle>,
+        poll_interval: Duration,
+        denom: String,
+    ) -> Self {
+        let cache = Arc::new(RwLock::new(None));
+
+        let task_cache = cache.clone();
+        let handle = tokio::spawn(async move {
+            let mut ticker = interval(poll_interval);
+
+            loop {
+                ticker.tick().await;
+
+                match oracle.query(&chain_id).await {
+                    Ok(categories) => {
+                        debug!(
+                            "fetched gas price oracle categories for chain '{}': {:?}",
+                            chain_id, categories
+                        );
+
+                        let mut guard = task_cache.write().unwrap();
+                        *guard = Some(CachedGasPrice {
+                            categories,
+                            fetched_at: Instant::now(),
+                        });
+                    }
+                    Err(e) => {
+                        warn!(
+                            "failed to fetch gas price oracle categories for chain '{}': {}",
+                            chain_id, e
+                        );
+                    }
+                }
+            }
+        });
+
+        Self {
+            denom,
+            cache,
+            handle: Some(handle),
+        }
+    }
+
+    /// Return the cached price for `speed` in `denom`, provided `denom`
+    /// matches the oracle's own denom and the cached value is no older
+    /// than `max_age`. Returns `None` if `denom` doesn't match, or the
+    /// cache is empty or stale, in which case the caller should fall back
+    /// to the chain's static gas price.
+    pub fn get(&self, speed: GasPriceSpeed, denom: &str, max_age: Duration) -> Option<GasPrice> {
+        if denom != self.denom {
+            return None;
+        }
+
+        let guard = self.cache.read().unwrap();
+        let cached = guard.as_ref()?;
+
+        if cached.fetched_at.elapsed() > max_age {
+            // Expected during a transient oracle outage, and this sits on
+            // the fee-calculation hot path, so stay quiet rather than
+            // flooding the logs on every call while stale.
+            debug!(
+                "gas price oracle cache is stale (older than {:?}), falling back to static gas price",
+                max_age
+            );
+            return None;
+        }
+
+        let price = cached.categories.price_for(speed);
+
+        Some(GasPrice::new(price, denom.to_string()))
+    }
+}
+
+impl Drop for GasPricePoller {
+    /// Stop the polling task so it doesn't keep running (and holding its
+    /// HTTP client open) once nothing references this poller anymore, e.g.
+    /// after a config reload rebuilds the `GasConfig` for a chain.
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+
+    fn categories() -> GasPriceCategories {
+        GasPriceCategories {
+            safe: 1.0,
+            standard: 2.0,
+            fast: 3.0,
+            fastest: 4.0,
+            recommended_base_fee: 1.5,
+        }
+    }
+
+    fn poller_with(categories: GasPriceCategories) -> GasPricePoller {
+        GasPricePoller {
+            denom: "uatom".to_string(),
+            cache: Arc::new(RwLock::new(Some(CachedGasPrice {
+                categories,
+                fetched_at: Instant::now(),
+            }))),
+            handle: None,
+        }
+    }
+
+    #[test]
+    fn returns_cached_price_for_selected_speed_when_fresh() {
+        let poller = poller_with(categories());
+
+        let price = poller
+            .get(GasPriceSpeed::Fast, "uatom", Duration::from_secs(30))
+            .unwrap();
+
+        assert_eq!(price.denom(), "uatom");
+        assert_eq!(price.price, 3.0);
+    }
+
+    #[test]
+    fn never_clamps_price_to_recommended_base_fee() {
+        let poller = poller_with(categories());
+
+        let price = poller
+            .get(GasPriceSpeed::Safe, "uatom", Duration::from_secs(30))
+            .unwrap();
+
+        // `safe` (1.0) is below `recommended_base_fee` (1.5); an operator
+        // who configured `safe` gets exactly that quote, not a floor.
+        assert_eq!(price.price, 1.0);
+    }
+
+    #[test]
+    fn falls_back_to_none_once_cache_is_stale() {
+        let poller = poller_with(categories());
+
+        sleep(Duration::from_millis(5));
+
+        let price = poller.get(GasPriceSpeed::Fast, "uatom", Duration::from_millis(1));
+
+        assert!(price.is_none());
+    }
+
+    #[test]
+    fn returns_none_for_a_denom_the_oracle_does_not_quote() {
+        let poller = poller_with(categories());
+
+        let price = poller.get(GasPriceSpeed::Fast, "ibc/a-foreign-denom", Duration::from_secs(30));
+
+        assert!(price.is_none());
+    }
+
+    #[test]
+    fn returns_none_when_cache_is_empty() {
+        let poller = GasPricePoller {
+            denom: "uatom".to_string(),
+            cache: Arc::new(RwLock::new(None)),
+            handle: None,
+        };
+
+        let price = poller.get(GasPriceSpeed::Standard, "uatom", Duration::from_secs(30));
+
+        assert!(price.is_none());
+    }
+
+    struct CountingOracle {
+        queries: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl GasPriceOracle for CountingOracle {
+        async fn query(&self, _chain_id: &str) -> Result<GasPriceCategories, Error> {
+            self.queries
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(categories())
+        }
+    }
+
+    #[tokio::test]
+    async fn dropping_the_poller_stops_its_polling_task() {
+        let queries = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let poller = GasPricePoller::spawn(
+            "chain".to_string(),
+            Arc::new(CountingOracle {
+                queries: queries.clone(),
+            }),
+            Duration::from_millis(1),
+            "uatom".to_string(),
+        );
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let queried_while_alive = queries.load(std::sync::atomic::Ordering::SeqCst);
+        assert!(queried_while_alive > 0);
+
+        drop(poller);
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let queried_after_drop = queries.load(std::sync::atomic::Ordering::SeqCst);
+        assert_eq!(queried_after_drop, queried_while_alive);
+    }
+}
@@ -0,0 +1,220 @@
+/*!
+   A profitability gate weighing the ICS29 incentive a packet would pay
+   against the estimated on-chain gas cost of relaying it, so that
+   unprofitable packets can be deferred rather than relayed at a loss.
+*/
+
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use serde::Deserialize;
+
+use crate::chain::cosmos::types::gas::{fee_coin_for_gas, GasConfig};
+use crate::error::Error;
+
+/// Per-chain profitability thresholds for ICS29-incentivized relaying.
+/// Configured per chain as `profitability`. The gate is disabled by
+/// default, preserving the current always-relay behavior; any field left
+/// unset in a chain's `profitability` table falls back to its `Default`.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct ProfitabilityConfig {
+    /// When `false`, [`evaluate`](ProfitabilityConfig::evaluate) always
+    /// returns [`RelayDecision::Relay`] without even estimating gas cost,
+    /// preserving the always-relay behavior of a chain that hasn't opted
+    /// into the gate.
+    pub enabled: bool,
+    /// The minimum reward/cost margin, as a percentage, below which a
+    /// packet is deferred rather than relayed.
+    pub min_profit_pct: f64,
+    /// The reward/cost margin, as a percentage, above which a packet is
+    /// prioritized over other relayable packets.
+    pub target_profit_pct: f64,
+    /// The reward/cost margin, as a percentage, above which a reward is
+    /// treated as implausible rather than genuinely profitable -- most
+    /// likely a misconfigured fee oracle or a misbehaving fee payer -- and
+    /// deferred for manual review rather than blindly prioritized.
+    pub max_profit_pct: f64,
+}
+
+/// The outcome of weighing an ICS29 reward against the estimated gas cost
+/// of relaying the packet that earns it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelayDecision {
+    /// The reward clears `target_profit_pct` but not `max_profit_pct`;
+    /// relay ahead of plain `Relay` packets.
+    Prioritize,
+    /// The reward clears `min_profit_pct` but not `target_profit_pct`; relay.
+    Relay,
+    /// The reward falls short of `min_profit_pct`, or clears
+    /// `max_profit_pct` and is treated as implausible; defer and retry
+    /// later, since gas prices fluctuate and the packet may become
+    /// profitable (or the implausible reward may turn out to be real).
+    Defer,
+}
+
+impl ProfitabilityConfig {
+    /// Estimate the on-chain gas cost of relaying at `estimated_gas`, using
+    /// the chain's effective fee price, and weigh `reward` (the claimable
+    /// ICS29 fee) against it. Always returns [`RelayDecision::Relay`]
+    /// without estimating anything when the gate is disabled.
+    ///
+    /// `reward` and the estimated cost must be denominated in the same
+    /// fee denom; a mismatch is reported rather than silently compared.
+    pub fn evaluate(
+        &self,
+        gas_config: &GasConfig,
+        estimated_gas: u64,
+        reward: &Coin,
+    ) -> Result<RelayDecision, Error> {
+        if !self.enabled {
+            return Ok(RelayDecision::Relay);
+        }
+
+        // Shares `fee_coin_for_gas` with `fee_for_gas` so the gate weighs
+        // exactly the amount that would actually be submitted as the fee.
+        let cost = fee_coin_for_gas(gas_config, estimated_gas);
+
+        if reward.denom != cost.denom {
+            return Err(Error::mismatched_fee_denom(
+                reward.denom.clone(),
+                cost.denom.clone(),
+            ));
+        }
+
+        let reward_amount: u128 = reward
+            .amount
+            .parse()
+            .map_err(|_| Error::invalid_fee_amount(reward.amount.clone()))?;
+        let cost_amount: u128 = cost
+            .amount
+            .parse()
+            .map_err(|_| Error::invalid_fee_amount(cost.amount.clone()))?;
+
+        Ok(self.decide(reward_amount, cost_amount))
+    }
+
+    /// Weigh a `reward` against a `cost`, both already normalized to the
+    /// same denom.
+    fn decide(&self, reward: u128, cost: u128) -> RelayDecision {
+        if reward >= scaled(cost, self.max_profit_pct) {
+            RelayDecision::Defer
+        } else if reward >= scaled(cost, self.target_profit_pct) {
+            RelayDecision::Prioritize
+        } else if reward >= scaled(cost, self.min_profit_pct) {
+            RelayDecision::Relay
+        } else {
+            RelayDecision::Defer
+        }
+    }
+}
+
+/// Scale `cost` up by `profit_pct` percent, rounding up so that the
+/// required reward is never under-estimated.
+fn scaled(cost: u128, profit_pct: f64) -> u128 {
+    ((cost as f64) * (100.0 + profit_pct) / 100.0).ceil() as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use ibc_proto::cosmos::tx::v1beta1::Fee;
+
+    use super::*;
+    use crate::chain::cosmos::types::gas_price_oracle::GasPriceSpeed;
+    use crate::config::dynamic_gas::DynamicGasPrice;
+    use crate::config::GasPrice;
+
+    fn config() -> ProfitabilityConfig {
+        ProfitabilityConfig {
+            enabled: true,
+            min_profit_pct: 10.0,
+            target_profit_pct: 50.0,
+            max_profit_pct: 150.0,
+        }
+    }
+
+    fn gas_config(denom: &str) -> GasConfig {
+        GasConfig {
+            default_gas: 100_000,
+            max_gas: 400_000,
+            gas_multiplier: 1.1,
+            gas_price: GasPrice::new(0.025, denom.to_string()),
+            fee_price: GasPrice::new(0.025, denom.to_string()),
+            max_fee: Fee::default(),
+            fee_granter: String::new(),
+            dynamic_gas_price: DynamicGasPrice::default(),
+            gas_price_speed: GasPriceSpeed::default(),
+            oracle_poller: None,
+            oracle_max_age: Duration::from_secs(30),
+            profitability: ProfitabilityConfig::default(),
+        }
+    }
+
+    fn coin(amount: u128, denom: &str) -> Coin {
+        Coin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    #[test]
+    fn scaled_rounds_up() {
+        assert_eq!(scaled(100, 10.0), 110);
+        assert_eq!(scaled(3, 10.0), 4);
+        assert_eq!(scaled(100, 0.0), 100);
+    }
+
+    #[test]
+    fn decide_prioritizes_at_or_above_target() {
+        let config = config();
+
+        assert_eq!(config.decide(150, 100), RelayDecision::Prioritize);
+        assert_eq!(config.decide(200, 100), RelayDecision::Prioritize);
+    }
+
+    #[test]
+    fn decide_relays_between_min_and_target() {
+        let config = config();
+
+        assert_eq!(config.decide(110, 100), RelayDecision::Relay);
+        assert_eq!(config.decide(149, 100), RelayDecision::Relay);
+    }
+
+    #[test]
+    fn decide_defers_below_min() {
+        let config = config();
+
+        assert_eq!(config.decide(109, 100), RelayDecision::Defer);
+        assert_eq!(config.decide(0, 100), RelayDecision::Defer);
+    }
+
+    #[test]
+    fn decide_defers_above_max_as_implausible() {
+        let config = config();
+
+        assert_eq!(config.decide(250, 100), RelayDecision::Defer);
+        assert_eq!(config.decide(1_000, 100), RelayDecision::Defer);
+    }
+
+    #[test]
+    fn evaluate_always_relays_when_disabled() {
+        let config = ProfitabilityConfig {
+            enabled: false,
+            ..config()
+        };
+
+        let decision = config
+            .evaluate(&gas_config("uatom"), 100_000, &coin(0, "uatom"))
+            .unwrap();
+
+        assert_eq!(decision, RelayDecision::Relay);
+    }
+
+    #[test]
+    fn evaluate_errors_on_mismatched_denom() {
+        let config = config();
+
+        let result = config.evaluate(&gas_config("uatom"), 100_000, &coin(1_000_000, "ibc/foreign"));
+
+        assert!(result.is_err());
+    }
+}
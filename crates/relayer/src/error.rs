@@ -0,0 +1,29 @@
+use flex_error::define_error;
+
+define_error! {
+    Error {
+        GasPriceOracle
+            { chain_id: String, reason: String }
+            |e| {
+                format_args!(
+                    "failed to query the gas price oracle for chain '{0}': {1}",
+                    e.chain_id, e.reason
+                )
+            },
+
+        MismatchedFeeDenom
+            { reward_denom: String, cost_denom: String }
+            |e| {
+                format_args!(
+                    "ICS29 fee reward denom '{0}' does not match the estimated gas cost denom '{1}'",
+                    e.reward_denom, e.cost_denom
+                )
+            },
+
+        InvalidFeeAmount
+            { amount: String }
+            |e| {
+                format_args!("invalid fee amount '{0}'", e.amount)
+            },
+    }
+}
@@ -0,0 +1,202 @@
+/*!
+   [`ChainDriver`] implementation for spawning and managing a Gaia full
+   node, following the standard Cosmos SDK CLI conventions (`gaiad start`,
+   `gaiad keys`, `gaiad tx`, `--fees`/`--gas-prices`).
+*/
+
+use alloc::sync::Arc;
+use std::process::Command;
+use tokio::runtime::Runtime;
+
+use ibc_relayer::chain::cosmos::types::config::TxConfig;
+use ibc_relayer::config::compat_mode::CompatMode;
+use ibc_relayer_types::applications::transfer::amount::Amount;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::chain::chain_type::ChainType;
+use crate::chain::cli::query::query_balance;
+use crate::chain::driver::{ChainDriver, ChainDriverCommon};
+use crate::error::Error;
+use crate::ibc::denom::Denom;
+use crate::relayer::tx::new_tx_config_for_test;
+use crate::types::env::{EnvWriter, ExportEnv};
+use crate::types::wallet::WalletAddress;
+
+/**
+   [`ChainDriver`] for a Gaia full node, following the standard Cosmos SDK
+   CLI conventions (`gaiad start`, `gaiad keys`, `gaiad tx`,
+   `--fees`/`--gas-prices`).
+*/
+#[derive(Debug, Clone)]
+pub struct GaiaChainDriver {
+    pub common: ChainDriverCommon,
+}
+
+impl ExportEnv for GaiaChainDriver {
+    fn export_env(&self, writer: &mut impl EnvWriter) {
+        writer.write_env("CMD", &self.command_path());
+        writer.write_env("HOME", &self.home_path());
+        writer.write_env("RPC_ADDR", &self.rpc_address());
+        writer.write_env("GRPC_ADDR", &self.grpc_address());
+    }
+}
+
+impl GaiaChainDriver {
+    /// Create a new [`GaiaChainDriver`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        chain_type: ChainType,
+        command_path: String,
+        chain_id: ChainId,
+        home_path: String,
+        account_prefix: String,
+        rpc_port: u16,
+        grpc_port: u16,
+        grpc_web_port: u16,
+        p2p_port: u16,
+        pprof_port: u16,
+        runtime: Arc<Runtime>,
+        native_token: String,
+        compat_mode: Option<CompatMode>,
+        ipv6_grpc: bool,
+    ) -> Result<Self, Error> {
+        let grpc_address = if ipv6_grpc {
+            format!("http://[::1]:{grpc_port}")
+        } else {
+            format!("http://localhost:{grpc_port}")
+        };
+        let tx_config = new_tx_config_for_test(
+            chain_id.clone(),
+            chain_type.clone(),
+            format!("http://localhost:{rpc_port}"),
+            grpc_address,
+            chain_type.address_type(),
+            native_token,
+        )?;
+
+        Ok(Self {
+            common: ChainDriverCommon::new(
+                chain_type,
+                command_path,
+                chain_id,
+                home_path,
+                account_prefix,
+                rpc_port,
+                grpc_port,
+                grpc_web_port,
+                p2p_port,
+                pprof_port,
+                runtime,
+                compat_mode,
+                ipv6_grpc,
+                tx_config,
+            ),
+        })
+    }
+}
+
+impl ChainDriver for GaiaChainDriver {
+    fn chain_type(&self) -> ChainType {
+        self.common.chain_type()
+    }
+
+    fn chain_id(&self) -> &ChainId {
+        self.common.chain_id()
+    }
+
+    fn command_path(&self) -> &str {
+        self.common.command_path()
+    }
+
+    fn home_path(&self) -> &str {
+        self.common.home_path()
+    }
+
+    fn account_prefix(&self) -> &str {
+        self.common.account_prefix()
+    }
+
+    fn rpc_port(&self) -> u16 {
+        self.common.rpc_port()
+    }
+
+    fn grpc_port(&self) -> u16 {
+        self.common.grpc_port()
+    }
+
+    fn tx_config(&self) -> &TxConfig {
+        self.common.tx_config()
+    }
+
+    fn runtime(&self) -> &Arc<Runtime> {
+        self.common.runtime()
+    }
+
+    fn compat_mode(&self) -> Option<CompatMode> {
+        self.common.compat_mode()
+    }
+
+    fn rpc_listen_address(&self) -> String {
+        format!("tcp://localhost:{}", self.rpc_port())
+    }
+
+    fn grpc_listen_address(&self) -> String {
+        self.common.grpc_listen_address()
+    }
+
+    fn grpc_address(&self) -> String {
+        if self.common.ipv6_grpc {
+            format!("http://[::1]:{}", self.grpc_port())
+        } else {
+            format!("http://127.0.0.1:{}", self.grpc_port())
+        }
+    }
+
+    fn query_balance(&self, wallet_id: &WalletAddress, denom: &Denom) -> Result<Amount, Error> {
+        query_balance(
+            self.chain_id().as_str(),
+            self.command_path(),
+            &self.rpc_listen_address(),
+            &wallet_id.0,
+            &denom.to_string(),
+        )
+    }
+
+    fn start_command(&self) -> Command {
+        let mut command = Command::new(self.command_path());
+        command
+            .arg("start")
+            .arg("--home")
+            .arg(self.home_path())
+            .arg("--pruning")
+            .arg("nothing")
+            .arg("--grpc.address")
+            .arg(self.grpc_listen_address())
+            .arg("--rpc.laddr")
+            .arg(self.rpc_listen_address());
+        command
+    }
+
+    fn init_command(&self, moniker: &str) -> Command {
+        let mut command = Command::new(self.command_path());
+        command
+            .arg("init")
+            .arg(moniker)
+            .arg("--home")
+            .arg(self.home_path())
+            .arg("--chain-id")
+            .arg(self.chain_id().as_str());
+        command
+    }
+
+    fn tx_fee_flags(&self, fee_amount: &str, fee_denom: &str) -> Vec<String> {
+        vec!["--fees".to_string(), format!("{fee_amount}{fee_denom}")]
+    }
+
+    fn export_env(&self, writer: &mut dyn EnvWriter) {
+        writer.write_env("CMD", &self.command_path());
+        writer.write_env("HOME", &self.home_path());
+        writer.write_env("RPC_ADDR", &self.rpc_address());
+        writer.write_env("GRPC_ADDR", &self.grpc_address());
+    }
+}
@@ -0,0 +1,586 @@
+/*!
+   Definition of the [`ChainDriver`] trait, and the shared assertions built
+   on top of it. Per-chain-type strategies live in [`gaia`] and [`namada`].
+*/
+
+use alloc::sync::Arc;
+use core::time::Duration;
+use eyre::eyre;
+use sha2::{Digest, Sha256};
+use std::cmp::max;
+use std::process::Command;
+use tokio::runtime::Runtime;
+
+use ibc_relayer::chain::cosmos::types::config::TxConfig;
+use ibc_relayer::config::compat_mode::CompatMode;
+use ibc_relayer_types::applications::transfer::amount::Amount;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::chain::chain_type::ChainType;
+use crate::error::Error;
+use crate::ibc::denom::Denom;
+use crate::ibc::token::Token;
+use crate::types::env::EnvWriter;
+use crate::types::wallet::WalletAddress;
+use crate::util::retry::assert_eventually_succeed;
+
+pub mod gaia;
+pub mod namada;
+
+pub use gaia::GaiaChainDriver;
+pub use namada::NamadaChainDriver;
+
+/**
+   Number of times (seconds) to try and query a wallet to reach the
+   target amount, as used by [`ChainDriver::assert_eventual_wallet_amount`].
+
+   We set this to around 90 seconds to make sure that the tests still
+   pass in slower environments like the CI.
+
+   If you encounter retry error, try increasing this constant. If the
+   test is taking much longer to reach eventual consistency, it might
+   be indication of some underlying performance issues.
+*/
+pub const WAIT_WALLET_AMOUNT_ATTEMPTS: u16 = 90;
+
+/**
+   The fields and accessors shared by every [`ChainDriver`] implementation,
+   regardless of chain type: process location, chain identity, ports, and
+   the constructed [`TxConfig`] the relayer side uses to talk to it.
+
+   [`GaiaChainDriver`] and [`NamadaChainDriver`] each embed one of these
+   rather than redeclaring the same fields (and field docs) twice; what
+   differs between chain types is how the binary is spawned, not what it
+   takes to locate and identify it.
+*/
+#[derive(Debug, Clone)]
+pub struct ChainDriverCommon {
+    pub chain_type: ChainType,
+
+    /**
+       The filesystem path to the chain's CLI binary.
+    */
+    pub command_path: String,
+
+    /**
+       The ID of the chain.
+    */
+    pub chain_id: ChainId,
+
+    /**
+       The home directory for the full node to store data files.
+    */
+    pub home_path: String,
+
+    pub account_prefix: String,
+
+    /**
+       The port used for RPC.
+    */
+    pub rpc_port: u16,
+
+    /**
+       The port used for GRPC.
+    */
+    pub grpc_port: u16,
+
+    pub grpc_web_port: u16,
+
+    /**
+       The port used for P2P. (Currently unused other than for setup)
+    */
+    pub p2p_port: u16,
+
+    /**
+       The port used for pprof. (Currently unused other than for setup)
+    */
+    pub pprof_port: u16,
+
+    pub tx_config: TxConfig,
+
+    pub runtime: Arc<Runtime>,
+
+    pub compat_mode: Option<CompatMode>,
+
+    pub ipv6_grpc: bool,
+}
+
+impl ChainDriverCommon {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        chain_type: ChainType,
+        command_path: String,
+        chain_id: ChainId,
+        home_path: String,
+        account_prefix: String,
+        rpc_port: u16,
+        grpc_port: u16,
+        grpc_web_port: u16,
+        p2p_port: u16,
+        pprof_port: u16,
+        runtime: Arc<Runtime>,
+        compat_mode: Option<CompatMode>,
+        ipv6_grpc: bool,
+        tx_config: TxConfig,
+    ) -> Self {
+        Self {
+            chain_type,
+            command_path,
+            chain_id,
+            home_path,
+            account_prefix,
+            rpc_port,
+            grpc_port,
+            grpc_web_port,
+            p2p_port,
+            pprof_port,
+            tx_config,
+            runtime,
+            compat_mode,
+            ipv6_grpc,
+        }
+    }
+
+    fn chain_type(&self) -> ChainType {
+        self.chain_type.clone()
+    }
+
+    fn chain_id(&self) -> &ChainId {
+        &self.chain_id
+    }
+
+    fn command_path(&self) -> &str {
+        &self.command_path
+    }
+
+    fn home_path(&self) -> &str {
+        &self.home_path
+    }
+
+    fn account_prefix(&self) -> &str {
+        &self.account_prefix
+    }
+
+    fn rpc_port(&self) -> u16 {
+        self.rpc_port
+    }
+
+    fn grpc_port(&self) -> u16 {
+        self.grpc_port
+    }
+
+    fn tx_config(&self) -> &TxConfig {
+        &self.tx_config
+    }
+
+    fn runtime(&self) -> &Arc<Runtime> {
+        &self.runtime
+    }
+
+    fn compat_mode(&self) -> Option<CompatMode> {
+        self.compat_mode.clone()
+    }
+
+    fn grpc_listen_address(&self) -> String {
+        if self.ipv6_grpc {
+            format!("[::1]:{}", self.grpc_port)
+        } else {
+            format!("127.0.0.1:{}", self.grpc_port)
+        }
+    }
+}
+
+/**
+    A driver for interacting with a chain full node through the command
+    line.
+
+    The name `ChainDriver` is inspired by
+    [WebDriver](https://developer.mozilla.org/en-US/docs/Web/WebDriver),
+    which is the term used to describe programs that control spawning of the
+    web browsers. In our case, the ChainDriver is used to spawn and manage
+    chain full nodes.
+
+    `ChainDriver` used to be a single struct hardcoded to support only a
+    single version of Gaia. It is now a trait, so that each chain type can
+    provide its own strategy for address prefix, binary flags, and genesis
+    setup (see [`GaiaChainDriver`] and [`NamadaChainDriver`]), while
+    integration tests keep relying on the same assertions regardless of
+    which chain binary is actually running. [`create`] is the factory that
+    dispatches on [`ChainType`] to produce the right implementation.
+*/
+pub trait ChainDriver: Send + Sync {
+    /// The type of the chain this driver spawns and manages.
+    fn chain_type(&self) -> ChainType;
+
+    /// The ID of the chain.
+    fn chain_id(&self) -> &ChainId;
+
+    /// The filesystem path to the chain's CLI binary.
+    fn command_path(&self) -> &str;
+
+    /// The home directory for the full node to store data files.
+    fn home_path(&self) -> &str;
+
+    /// The bech32 address prefix used by the chain.
+    fn account_prefix(&self) -> &str;
+
+    /// The port used for RPC.
+    fn rpc_port(&self) -> u16;
+
+    /// The port used for GRPC.
+    fn grpc_port(&self) -> u16;
+
+    fn tx_config(&self) -> &TxConfig;
+
+    fn runtime(&self) -> &Arc<Runtime>;
+
+    fn compat_mode(&self) -> Option<CompatMode>;
+
+    /**
+        Returns the full URL for the RPC address to listen to when starting
+        the full node.
+
+        This is somehow different from [`rpc_address`](ChainDriver::rpc_address)
+        as it requires the chain's own listen-address scheme/flags.
+    */
+    fn rpc_listen_address(&self) -> String;
+
+    /**
+        Returns the full URL for the GRPC address to listen to when starting
+        the full node.
+
+        This is somehow different from [`grpc_address`](ChainDriver::grpc_address)
+        as it requires no scheme to be specified.
+    */
+    fn grpc_listen_address(&self) -> String;
+
+    /**
+       Query for the balances for a given wallet address and denomination
+    */
+    fn query_balance(&self, wallet_id: &WalletAddress, denom: &Denom) -> Result<Amount, Error>;
+
+    /**
+       Build the [`Command`] that starts this chain's full node process,
+       with whatever binary, subcommand and flags this chain type's
+       conventions require (e.g. a Cosmos SDK chain's `<binary> start` vs
+       [`NamadaChainDriver`]'s `namada ledger run`).
+    */
+    fn start_command(&self) -> Command;
+
+    /**
+       Build the [`Command`] that initializes this chain's home directory
+       with a fresh genesis, under the given validator `moniker`, before
+       it can be started.
+    */
+    fn init_command(&self, moniker: &str) -> Command;
+
+    /**
+       The CLI flags used to attach a transaction fee of `fee_amount` in
+       `fee_denom` when submitting a tx through this chain's binary (e.g.
+       `--fees <amount><denom>` for a Cosmos SDK chain).
+    */
+    fn tx_fee_flags(&self, fee_amount: &str, fee_denom: &str) -> Vec<String>;
+
+    /**
+       Write this driver's environment variables (CLI path, home directory,
+       RPC/GRPC addresses) to `writer`.
+
+       This takes `&mut dyn EnvWriter` rather than the `&mut impl EnvWriter`
+       [`ExportEnv`](crate::types::env::ExportEnv) uses elsewhere in the
+       framework, since [`ChainDriver`] is used as `Box<dyn ChainDriver>`
+       and a generic method on a trait is not object-safe: it would have no
+       single vtable entry to call through. Implementations still usually
+       also implement [`ExportEnv`](crate::types::env::ExportEnv) directly
+       for callers that hold a concrete type.
+    */
+    fn export_env(&self, writer: &mut dyn EnvWriter);
+
+    /// Returns the full URL for the RPC address.
+    fn rpc_address(&self) -> String {
+        format!("http://localhost:{}", self.rpc_port())
+    }
+
+    /// Returns the full URL for the WebSocket address.
+    fn websocket_address(&self) -> String {
+        format!("ws://localhost:{}/websocket", self.rpc_port())
+    }
+
+    /// Returns the full URL for the GRPC address.
+    fn grpc_address(&self) -> String {
+        format!("http://127.0.0.1:{}", self.grpc_port())
+    }
+
+    /**
+       Initialize this chain's home directory under `moniker` via
+       [`init_command`](ChainDriver::init_command), then spawn its full
+       node process via [`start_command`](ChainDriver::start_command).
+
+       This is the actual caller that drives the per-chain-type spawn and
+       genesis strategy: bootstrapping a node means running exactly these
+       two commands in order, so the trait provides that sequencing once
+       rather than leaving every chain type (or every test) to reimplement
+       it atop the lower-level [`init_command`](ChainDriver::init_command)/
+       [`start_command`](ChainDriver::start_command) building blocks.
+    */
+    fn bootstrap(&self, moniker: &str) -> Result<std::process::Child, Error> {
+        let status = self.init_command(moniker).status().map_err(|e| {
+            Error::generic(eyre!(
+                "failed to run init command for chain '{}': {e}",
+                self.chain_id()
+            ))
+        })?;
+
+        if !status.success() {
+            return Err(Error::generic(eyre!(
+                "init command for chain '{}' exited with {status}",
+                self.chain_id()
+            )));
+        }
+
+        self.start_command().spawn().map_err(|e| {
+            Error::generic(eyre!(
+                "failed to spawn full node process for chain '{}': {e}",
+                self.chain_id()
+            ))
+        })
+    }
+
+    /**
+       Build the [`Command`] for submitting a transaction through this
+       chain's binary: `args` followed by the fee flags from
+       [`tx_fee_flags`](ChainDriver::tx_fee_flags) for `fee_amount`/`fee_denom`.
+    */
+    fn tx_command(&self, args: &[&str], fee_amount: &str, fee_denom: &str) -> Command {
+        let mut command = Command::new(self.command_path());
+        command.args(args);
+        command.args(self.tx_fee_flags(fee_amount, fee_denom));
+        command
+    }
+
+    /**
+       Assert that a wallet should eventually have the expected amount in the
+       given denomination.
+    */
+    fn assert_eventual_wallet_amount(
+        &self,
+        wallet: &WalletAddress,
+        token: &Token,
+    ) -> Result<(), Error> {
+        assert_eventually_succeed(
+            &format!("wallet reach {wallet} amount {token}"),
+            WAIT_WALLET_AMOUNT_ATTEMPTS,
+            Duration::from_secs(1),
+            || {
+                let amount: Amount = self.query_balance(wallet, &token.denom)?;
+
+                if amount == token.amount {
+                    Ok(())
+                } else {
+                    Err(Error::generic(eyre!(
+                        "current balance of account {} with amount {} does not match the target amount {}",
+                        wallet,
+                        amount,
+                        token
+                    )))
+                }
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /**
+       Assert that a wallet should eventually have escrowed the amount for ICS29
+       fees of a given denomination.
+       Legacy ICS29 will escrow recv_fee + ack_fee + timeout_fee while more recent
+       versions will escrow max(recv_fee + ack_fee, timeout_fee).
+    */
+    fn assert_eventual_escrowed_amount_ics29(
+        &self,
+        wallet: &WalletAddress,
+        token: &Token,
+        recv_fee: u128,
+        ack_fee: u128,
+        timeout_fee: u128,
+    ) -> Result<(), Error> {
+        assert_eventually_succeed(
+            &format!("wallet reach {wallet} amount {token}"),
+            WAIT_WALLET_AMOUNT_ATTEMPTS,
+            Duration::from_secs(1),
+            || {
+                let amount: Amount = self.query_balance(wallet, &token.denom)?;
+
+                let legacy_escrow = token
+                    .amount
+                    .checked_sub(recv_fee + ack_fee + timeout_fee)
+                    .ok_or_else(|| {
+                        Error::generic(eyre!(
+                            "error computing the following subtraction: {}-{}",
+                            token.amount,
+                            recv_fee + ack_fee + timeout_fee
+                        ))
+                    })?;
+                let escrow = token
+                    .amount
+                    .checked_sub(max(recv_fee + ack_fee, timeout_fee))
+                    .ok_or_else(|| {
+                        Error::generic(eyre!(
+                            "error computing the following subtraction: {}-{}",
+                            token.amount,
+                            max(recv_fee + ack_fee, timeout_fee)
+                        ))
+                    })?;
+
+                // Assert either the legacy or current ICS29 amount has been escrowed
+                if amount == legacy_escrow || amount == escrow {
+                    Ok(())
+                } else {
+                    Err(Error::generic(eyre!(
+                        "current balance of account {} with amount {} does not match the target amount {}",
+                        wallet,
+                        amount,
+                        token
+                    )))
+                }
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /**
+       Query for the balance of a wallet in the IBC denom derived from
+       transferring `base_denom` over the given `trace_path` (e.g.
+       `"transfer/channel-0"`).
+    */
+    fn query_ibc_token_balance(
+        &self,
+        wallet_id: &WalletAddress,
+        trace_path: &str,
+        base_denom: &str,
+    ) -> Result<Amount, Error> {
+        self.query_balance(wallet_id, &ibc_denom(trace_path, base_denom))
+    }
+
+    /**
+       Assert that a wallet's balance in the IBC-transferred fee token,
+       computed from `trace_path`/`base_denom`, eventually decreases by
+       `fee_amount` from `initial_amount`.
+
+       This mirrors [`assert_eventual_wallet_amount`](ChainDriver::assert_eventual_wallet_amount)
+       and [`assert_eventual_escrowed_amount_ics29`](ChainDriver::assert_eventual_escrowed_amount_ics29),
+       but for confirming that a chain deducted a transaction fee in a
+       foreign, IBC-transferred token rather than its native one.
+    */
+    fn assert_eventual_fee_paid_in_ibc_token(
+        &self,
+        wallet: &WalletAddress,
+        trace_path: &str,
+        base_denom: &str,
+        initial_amount: Amount,
+        fee_amount: u128,
+    ) -> Result<(), Error> {
+        let denom = ibc_denom(trace_path, base_denom);
+
+        let expected_amount = initial_amount.checked_sub(fee_amount).ok_or_else(|| {
+            Error::generic(eyre!(
+                "error computing the following subtraction: {}-{}",
+                initial_amount,
+                fee_amount
+            ))
+        })?;
+
+        assert_eventually_succeed(
+            &format!("wallet {wallet} paid fee {fee_amount} in ibc denom {denom}"),
+            WAIT_WALLET_AMOUNT_ATTEMPTS,
+            Duration::from_secs(1),
+            || {
+                let amount: Amount = self.query_balance(wallet, &denom)?;
+
+                if amount == expected_amount {
+                    Ok(())
+                } else {
+                    Err(Error::generic(eyre!(
+                        "current balance of account {} with amount {} does not match the expected post-fee amount {}",
+                        wallet,
+                        amount,
+                        expected_amount
+                    )))
+                }
+            },
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Compute the `ibc/<hash>` denom that a chain derives for a token
+/// transferred to it over `trace_path` (e.g. `"transfer/channel-0"`),
+/// following the ICS20 denom trace hashing algorithm: the hex-encoded,
+/// upper-cased SHA-256 digest of `"<trace_path>/<base_denom>"`.
+fn ibc_denom(trace_path: &str, base_denom: &str) -> Denom {
+    let full_trace = format!("{trace_path}/{base_denom}");
+    let digest = Sha256::digest(full_trace.as_bytes());
+    let hash = digest.iter().map(|byte| format!("{byte:02X}")).collect::<String>();
+
+    Denom::Ibc(hash)
+}
+
+/// Create a [`ChainDriver`] implementation appropriate for `chain_type`.
+///
+/// This is the single place that dispatches on [`ChainType`], so that
+/// adding a new chain binary to the test framework means adding a new
+/// variant here and a matching module, rather than forking the whole
+/// driver.
+#[allow(clippy::too_many_arguments)]
+pub fn create(
+    chain_type: ChainType,
+    command_path: String,
+    chain_id: ChainId,
+    home_path: String,
+    account_prefix: String,
+    rpc_port: u16,
+    grpc_port: u16,
+    grpc_web_port: u16,
+    p2p_port: u16,
+    pprof_port: u16,
+    runtime: Arc<Runtime>,
+    native_token: String,
+    compat_mode: Option<CompatMode>,
+    ipv6_grpc: bool,
+) -> Result<Box<dyn ChainDriver>, Error> {
+    match chain_type {
+        ChainType::Namada => Ok(Box::new(NamadaChainDriver::create(
+            chain_type,
+            command_path,
+            chain_id,
+            home_path,
+            account_prefix,
+            rpc_port,
+            grpc_port,
+            grpc_web_port,
+            p2p_port,
+            pprof_port,
+            runtime,
+            native_token,
+            compat_mode,
+            ipv6_grpc,
+        )?)),
+        _ => Ok(Box::new(GaiaChainDriver::create(
+            chain_type,
+            command_path,
+            chain_id,
+            home_path,
+            account_prefix,
+            rpc_port,
+            grpc_port,
+            grpc_web_port,
+            p2p_port,
+            pprof_port,
+            runtime,
+            native_token,
+            compat_mode,
+            ipv6_grpc,
+        )?)),
+    }
+}